@@ -159,12 +159,34 @@ pub fn derive_stargate(input: TokenStream) -> TokenStream {
 ///     MsgTransfer,
 ///     ... // Others enum fields
 /// }
+/// ```
+///
+/// Also implements:
+/// - `TryFrom<String>`, delegating to `from_str`;
+/// - `fn all_urls() -> Vec<String>`, returning the serialized value of each variant.
 #[proc_macro_attribute]
 pub fn urls(_attr: proc_macro::TokenStream, input: proc_macro::TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
+    let enum_name = &input.ident;
     let expanded = quote! {
         #[derive(strum_macros::EnumString, strum_macros::EnumIter, strum_macros::Display)]
         #input
+
+        impl ::std::convert::TryFrom<String> for #enum_name {
+            type Error = strum::ParseError;
+
+            fn try_from(value: String) -> Result<Self, Self::Error> {
+                <Self as ::std::str::FromStr>::from_str(&value)
+            }
+        }
+
+        impl #enum_name {
+            pub fn all_urls() -> Vec<String> {
+                <Self as strum::IntoEnumIterator>::iter()
+                    .map(|url| url.to_string())
+                    .collect()
+            }
+        }
     };
     TokenStream::from(expanded)
 }
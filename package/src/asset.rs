@@ -12,7 +12,7 @@ use {
     cw_asset::{Asset, AssetError, AssetInfo},
     cw_storage_plus::{Key, KeyDeserialize, Prefixer, PrimaryKey},
     serde::Serialize,
-    std::{any::type_name, collections::HashMap, fmt::Display},
+    std::{any::type_name, collections::HashMap, fmt::Display, str::FromStr},
 };
 
 /// Check if `coins` has a `len() == 1`.
@@ -149,6 +149,36 @@ impl AssetInfoExstender for AssetInfo {
 }
 
 /// Wrapper container for [AssetInfo] for inclding also token precision
+///
+/// Implements `JsonSchema`, so it can be used in a custom message `C` of a `ContractWrapper`.
+/// The custom message itself has to implement `JsonSchema` too, otherwise the contract doesn't compile:
+///
+/// ```compile_fail
+/// use {
+///     cosmwasm_std::{Binary, Deps, DepsMut, Empty, Env, MessageInfo, Response, StdResult},
+///     rhaki_cw_plus::{asset::AssetInfoPrecisioned, multi_test::helper::create_code},
+/// };
+///
+/// // Missing `JsonSchema`
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct CustomMsg {
+///     info: AssetInfoPrecisioned,
+/// }
+///
+/// fn instantiate(_: DepsMut, _: Env, _: MessageInfo, _: Empty) -> StdResult<Response<CustomMsg>> {
+///     Ok(Response::new())
+/// }
+///
+/// fn execute(_: DepsMut, _: Env, _: MessageInfo, _: Empty) -> StdResult<Response<CustomMsg>> {
+///     Ok(Response::new())
+/// }
+///
+/// fn query(_: Deps, _: Env, _: Empty) -> StdResult<Binary> {
+///     Ok(Binary::default())
+/// }
+///
+/// create_code(instantiate, execute, query);
+/// ```
 #[cw_serde]
 #[non_exhaustive]
 pub struct AssetInfoPrecisioned {
@@ -454,6 +484,31 @@ impl AssetAmount {
     }
 }
 
+/// Display as `P:{amount}` for [AssetAmount::Precisioned] and `R:{amount}` for [AssetAmount::Precisionless]
+impl Display for AssetAmount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AssetAmount::Precisioned(amount) => write!(f, "P:{amount}"),
+            AssetAmount::Precisionless(amount) => write!(f, "R:{amount}"),
+        }
+    }
+}
+
+/// Parse the same format of [Display]: `P:{amount}` or `R:{amount}`
+impl FromStr for AssetAmount {
+    type Err = StdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once(':') {
+            Some(("P", amount)) => Ok(AssetAmount::Precisioned(Decimal::from_str(amount)?)),
+            Some(("R", amount)) => Ok(AssetAmount::Precisionless(Uint128::from_str(amount)?)),
+            _ => Err(StdError::generic_err(format!(
+                "Invalid AssetAmount format, found: {s}, expected: P:{{amount}} or R:{{amount}}"
+            ))),
+        }
+    }
+}
+
 impl From<Uint128> for AssetAmount {
     fn from(value: Uint128) -> Self {
         Self::Precisionless(value)
@@ -724,3 +779,66 @@ fn math() {
         b + "200".into_decimal()
     );
 }
+
+#[test]
+fn asset_amount_str() {
+    let precisioned = AssetAmount::Precisioned("1.5".into_decimal());
+    let precisionless = AssetAmount::Precisionless(1_500_000_u128.into());
+
+    assert_eq!(precisioned.to_string(), "P:1.5");
+    assert_eq!(precisionless.to_string(), "R:1500000");
+
+    assert_eq!(AssetAmount::from_str("P:1.5").unwrap(), precisioned);
+    assert_eq!(AssetAmount::from_str("R:1500000").unwrap(), precisionless);
+
+    AssetAmount::from_str("1.5").unwrap_err();
+    AssetAmount::from_str("X:1.5").unwrap_err();
+    AssetAmount::from_str("R:1.5").unwrap_err();
+}
+
+#[cfg(feature = "multi-test")]
+#[test]
+fn test_asset_custom_msg() {
+    use {
+        crate::multi_test::helper::create_code,
+        cosmwasm_schema::schema_for,
+        cosmwasm_std::{Binary, Deps, DepsMut, Empty, Env, MessageInfo, Response},
+    };
+
+    #[cw_serde]
+    struct CustomMsg {
+        info: AssetInfoPrecisioned,
+        asset: AssetPrecisioned,
+    }
+
+    fn instantiate(
+        _deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        _msg: Empty,
+    ) -> StdResult<Response<CustomMsg>> {
+        Ok(Response::new())
+    }
+
+    fn execute(
+        _deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        _msg: Empty,
+    ) -> StdResult<Response<CustomMsg>> {
+        Ok(Response::new())
+    }
+
+    fn query(_deps: Deps, _env: Env, _msg: Empty) -> StdResult<Binary> {
+        Ok(Binary::default())
+    }
+
+    // `ContractWrapper` requires `C: JsonSchema`, so this only compile if
+    // `AssetInfoPrecisioned` and `AssetPrecisioned` fully implement `JsonSchema`
+    create_code(instantiate, execute, query);
+
+    let schema = serde_json::to_string(&schema_for!(CustomMsg)).unwrap();
+
+    assert!(schema.contains("AssetInfoPrecisioned"));
+    assert!(schema.contains("AssetPrecisioned"));
+}
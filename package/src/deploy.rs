@@ -2,6 +2,7 @@ use {
     crate::math::IntoDecimal,
     anyhow::anyhow,
     async_trait::async_trait,
+    cosmos_grpc_client::Wallet,
     cosmwasm_schema::cw_serde,
     cosmwasm_std::Decimal,
     serde::{de::DeserializeOwned, Serialize},
@@ -227,6 +228,29 @@ pub trait Deploier: Serialize + DeserializeOwned {
         Ok(())
     }
 
+    /// Lifecycle hook for custom validation (ex: check the on-chain state) before deploying.
+    ///
+    /// The hooks are not called by this crate: the deploy script has to invoke them, in the following order:
+    ///
+    /// ```ignore
+    /// data.pre_deploy(&wallet).await?;
+    ///
+    /// let code_id = store_code(&mut wallet, &data, "contract", None, None).await?;
+    /// let address = instantiate_contract(&mut wallet, None, code_id, "contract", msg, vec![], None).await?;
+    ///
+    /// data.post_deploy(&wallet).await?;
+    /// ```
+    async fn pre_deploy(&self, _wallet: &Wallet) -> AnyResult<()> {
+        Ok(())
+    }
+
+    /// Lifecycle hook for custom verification (ex: query the deployed contracts) after deploying.
+    ///
+    /// Not called by this crate, see [Deploier::pre_deploy] for the call order.
+    async fn post_deploy(&mut self, _wallet: &Wallet) -> AnyResult<()> {
+        Ok(())
+    }
+
     fn read_wasm_bytecode(&self, file_name: &str) -> AnyResult<Vec<u8>> {
         std::fs::read(format!("{}/{file_name}.wasm", Self::PATH_ARTIFACTS)).map_err(|_| {
             anyhow!(
@@ -311,7 +335,7 @@ impl Into<&str> for NetType {
 }
 
 pub mod functions {
-    use std::str::from_utf8;
+    use std::{str::from_utf8, time::Duration};
 
     use {
         anyhow::{anyhow, bail},
@@ -328,12 +352,15 @@ pub mod functions {
         },
         cosmwasm_std::{to_json_binary, Coin as StdCoin, StdError, StdResult},
         serde::Serialize,
+        tonic::{Code, Status},
     };
 
-    use crate::traits::IntoStdResult;
+    use crate::{encdec::base64_decode_as_string, traits::IntoStdResult};
 
     use super::{AnyResult, ChainInfo, Deploier, NetType};
 
+    const DEFAULT_MAX_RETRIES: u32 = 3;
+
     pub fn get_net_by_args() -> (NetType, String) {
         let args = std::env::args().collect::<Vec<String>>();
 
@@ -351,11 +378,16 @@ pub mod functions {
         )
     }
 
+    /// Store the wasm `file_name` on chain and return the `code_id`.
+    ///
+    /// The broadcast is retried up to `max_retries` times (default `3`) with exponential backoff
+    /// if the gRPC fails with a transient error (`ResourceExhausted` or `Unavailable`)
     pub async fn store_code(
         wallet: &mut Wallet,
         data: &impl Deploier,
         file_name: &str,
         instantiate_permission: Option<AccessConfig>,
+        max_retries: Option<u32>,
     ) -> AnyResult<u64> {
         print!("Storing {file_name}...");
         let bytes = data.read_wasm_bytecode(file_name)?;
@@ -367,9 +399,27 @@ pub mod functions {
         }
         .build_any(MsgStoreCode::type_url());
 
-        let res = wallet
-            .broadcast_tx(vec![msg], None, None, BroadcastMode::Sync)
-            .await?;
+        let max_retries = max_retries.unwrap_or(DEFAULT_MAX_RETRIES);
+        let mut attempt = 0;
+
+        let res = loop {
+            match wallet
+                .broadcast_tx(vec![msg.clone()], None, None, BroadcastMode::Sync)
+                .await
+            {
+                Ok(res) => break res,
+                Err(err) if attempt < max_retries && is_transient_grpc_error(&err) => {
+                    attempt += 1;
+                    let backoff = Duration::from_secs(2_u64.pow(attempt - 1));
+                    eprintln!(
+                        "\nStoring {file_name} failed: {err}. Retry {attempt}/{max_retries} in {}s",
+                        backoff.as_secs()
+                    );
+                    tokio::time::sleep(backoff).await;
+                },
+                Err(err) => return Err(err),
+            }
+        };
 
         if res.tx_response.as_ref().unwrap().code != 0 {
             bail!("Error: {}", res.tx_response.unwrap().raw_log)
@@ -386,6 +436,18 @@ pub mod functions {
         Ok(code_id)
     }
 
+    /// Check if the error is a gRPC `Status` that can succeed on retry.
+    ///
+    /// The `Status` is found only if `tonic` is the same version used by `cosmos-grpc-client`
+    pub(crate) fn is_transient_grpc_error(err: &anyhow::Error) -> bool {
+        err.chain().any(|cause| {
+            matches!(
+                cause.downcast_ref::<Status>().map(|status| status.code()),
+                Some(Code::ResourceExhausted | Code::Unavailable)
+            )
+        })
+    }
+
     pub async fn instantiate_contract<T: Serialize>(
         wallet: &mut Wallet,
         admin: Option<String>,
@@ -484,19 +546,44 @@ pub mod functions {
         bail!("not found")
     }
 
+    /// Search the contract address among the events, looking for `_contract_address` first and then `contract_address`
     pub fn get_address_from_init_response(response: GetTxResponse) -> StdResult<String> {
-        for event in response.tx_response.unwrap().events {
-            // if event.r#type == "instantiate".to_string() {
-            for attribute in event.attributes {
-                if attribute.key == "_contract_address".to_string() {
-                    return Ok(from_utf8(&attribute.value)?.to_string());
-                }
+        for key in ["_contract_address", "contract_address"] {
+            if let Some(address) = get_attribute_value(&response, key)? {
+                return Ok(address);
             }
-            // }
         }
         Err(StdError::generic_err("not found"))
     }
 
+    /// Search the value of the first attribute with `key` among all the events.
+    ///
+    /// Some chains return the attributes `base64` encoded: if the `key` match only once decoded, also the value is decoded
+    pub fn get_attribute_value(response: &GetTxResponse, key: &str) -> StdResult<Option<String>> {
+        let tx_response = response
+            .tx_response
+            .as_ref()
+            .ok_or(StdError::generic_err("Empty tx_response"))?;
+
+        for event in &tx_response.events {
+            for attribute in &event.attributes {
+                // Skip the attributes of unrelated events that are not valid utf8
+                let Ok(attribute_key) = from_utf8(&attribute.key) else {
+                    continue;
+                };
+
+                if attribute_key == key {
+                    return Ok(Some(from_utf8(&attribute.value)?.to_string()));
+                }
+
+                if base64_decode_as_string(attribute_key).is_ok_and(|decoded| decoded == key) {
+                    return base64_decode_as_string(from_utf8(&attribute.value)?).map(Some);
+                }
+            }
+        }
+        Ok(None)
+    }
+
     pub async fn deploy_create_wallet(
         client: &GrpcClient,
         chain_info: &ChainInfo,
@@ -514,3 +601,122 @@ pub mod functions {
         .await
     }
 }
+
+#[cfg(test)]
+mod test {
+    use cosmos_grpc_client::cosmos_sdk_proto::cosmos::{
+        base::abci::v1beta1::TxResponse,
+        tx::v1beta1::{service_client::ServiceClient, BroadcastTxRequest, GetTxResponse},
+    };
+
+    use crate::encdec::base64_encode;
+
+    use tonic::{Code, Status};
+
+    use super::functions::{get_address_from_init_response, is_transient_grpc_error};
+
+    fn mock_tx_response(attributes: Vec<(String, String)>) -> GetTxResponse {
+        let mut tx_response = TxResponse::default();
+        tx_response.events.push(Default::default());
+
+        let event = &mut tx_response.events[0];
+
+        for (key, value) in attributes {
+            event.attributes.push(Default::default());
+            let attribute = event.attributes.last_mut().unwrap();
+            attribute.key = key.into();
+            attribute.value = value.into();
+        }
+
+        GetTxResponse {
+            tx_response: Some(tx_response),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_address_from_init_response() {
+        let response = mock_tx_response(vec![
+            ("code_id".to_string(), "1".to_string()),
+            ("_contract_address".to_string(), "osmo1contract".to_string()),
+        ]);
+
+        assert_eq!(
+            get_address_from_init_response(response).unwrap(),
+            "osmo1contract"
+        );
+
+        let response = mock_tx_response(vec![
+            ("code_id".to_string(), "1".to_string()),
+            ("contract_address".to_string(), "osmo1contract".to_string()),
+        ]);
+
+        assert_eq!(
+            get_address_from_init_response(response).unwrap(),
+            "osmo1contract"
+        );
+
+        let response = mock_tx_response(vec![(
+            base64_encode("contract_address"),
+            base64_encode("osmo1contract"),
+        )]);
+
+        assert_eq!(
+            get_address_from_init_response(response).unwrap(),
+            "osmo1contract"
+        );
+
+        let mut response = mock_tx_response(vec![(
+            "_contract_address".to_string(),
+            "osmo1contract".to_string(),
+        )]);
+
+        let tx_response = response.tx_response.as_mut().unwrap();
+        tx_response.events.insert(0, Default::default());
+        tx_response.events[0].attributes.push(Default::default());
+        tx_response.events[0].attributes[0].key = vec![0xff, 0xfe].into();
+        tx_response.events[0].attributes[0].value = vec![0xff].into();
+
+        assert_eq!(
+            get_address_from_init_response(response).unwrap(),
+            "osmo1contract"
+        );
+
+        let response = mock_tx_response(vec![("code_id".to_string(), "1".to_string())]);
+
+        get_address_from_init_response(response).unwrap_err();
+    }
+
+    #[test]
+    fn test_transient_grpc_error() {
+        for code in [Code::ResourceExhausted, Code::Unavailable] {
+            assert!(is_transient_grpc_error(&anyhow::Error::from(Status::new(
+                code, "retry"
+            ))));
+        }
+
+        assert!(!is_transient_grpc_error(&anyhow::Error::from(Status::new(
+            Code::InvalidArgument,
+            "invalid"
+        ))));
+
+        assert!(is_transient_grpc_error(
+            &anyhow::Error::from(Status::new(Code::Unavailable, "retry")).context("broadcast")
+        ));
+
+        assert!(!is_transient_grpc_error(&anyhow::anyhow!("Unavailable")));
+    }
+
+    #[tokio::test]
+    async fn test_transient_grpc_error_from_client() {
+        // Compiles only if `tonic` is the same version used by `cosmos-grpc-client`
+        let channel = tonic::transport::Channel::from_static("http://127.0.0.1:1").connect_lazy();
+
+        let status = ServiceClient::new(channel)
+            .broadcast_tx(BroadcastTxRequest::default())
+            .await
+            .unwrap_err();
+
+        assert!(is_transient_grpc_error(&anyhow::Error::from(status)));
+    }
+}
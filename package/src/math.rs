@@ -234,6 +234,8 @@ pub trait DecimalExtend {
         denominator: B,
         or: Decimal,
     ) -> StdResult<Decimal>;
+    fn lerp(a: Decimal, b: Decimal, t: Decimal) -> StdResult<Decimal>;
+    fn inv_lerp(a: Decimal, b: Decimal, value: Decimal) -> StdResult<Decimal>;
 }
 
 impl DecimalExtend for Decimal {
@@ -255,6 +257,52 @@ impl DecimalExtend for Decimal {
             })
             .into_std_result()
     }
+
+    /// Linear interpolation between `a` and `b`: `a + t * (b - a)`.
+    ///
+    /// `t` must be in `[0, 1]`. `b` can be lower than `a`
+    fn lerp(a: Decimal, b: Decimal, t: Decimal) -> StdResult<Decimal> {
+        if t > Decimal::one() {
+            return Err(StdError::generic_err(format!(
+                "Invalid lerp, t must be in [0, 1], found: {t}"
+            )));
+        }
+
+        if b >= a {
+            Ok(a + t * (b - a))
+        } else {
+            Ok(a - t * (a - b))
+        }
+    }
+
+    /// Inverse of [DecimalExtend::lerp], return `t` such that `lerp(a, b, t) == value`.
+    ///
+    /// `value` must be between `a` and `b`, and `a` must be different from `b`
+    fn inv_lerp(a: Decimal, b: Decimal, value: Decimal) -> StdResult<Decimal> {
+        if a == b {
+            return Err(StdError::generic_err(format!(
+                "Invalid inv_lerp, a and b are equal: {a}"
+            )));
+        }
+
+        let (min, max) = if a < b {
+            (a, b)
+        } else {
+            (b, a)
+        };
+
+        if value < min || value > max {
+            return Err(StdError::generic_err(format!(
+                "Invalid inv_lerp, value {value} is not between {a} and {b}"
+            )));
+        }
+
+        if b > a {
+            Ok((value - a) / (b - a))
+        } else {
+            Ok((a - value) / (a - b))
+        }
+    }
 }
 
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
@@ -596,3 +644,32 @@ pub fn test_signed_decimal() {
     let b = -b;
     assert_eq!(b, SignedDecimal::from_str("100").unwrap());
 }
+
+#[test]
+pub fn test_lerp() {
+    let a = "10".into_decimal();
+    let b = "20".into_decimal();
+
+    assert_eq!(Decimal::lerp(a, b, Decimal::zero()).unwrap(), a);
+    assert_eq!(Decimal::lerp(a, b, Decimal::one()).unwrap(), b);
+    assert_eq!(
+        Decimal::lerp(a, b, "0.25".into_decimal()).unwrap(),
+        "12.5".into_decimal()
+    );
+    assert_eq!(
+        Decimal::lerp(b, a, "0.25".into_decimal()).unwrap(),
+        "17.5".into_decimal()
+    );
+    Decimal::lerp(a, b, "1.1".into_decimal()).unwrap_err();
+
+    assert_eq!(
+        Decimal::inv_lerp(a, b, "12.5".into_decimal()).unwrap(),
+        "0.25".into_decimal()
+    );
+    assert_eq!(
+        Decimal::inv_lerp(b, a, "17.5".into_decimal()).unwrap(),
+        "0.25".into_decimal()
+    );
+    Decimal::inv_lerp(a, b, "21".into_decimal()).unwrap_err();
+    Decimal::inv_lerp(a, a, a).unwrap_err();
+}
@@ -0,0 +1,160 @@
+use {
+    crate::{
+        multi_test::{
+            helper::cw_multi_test::error::AnyResult,
+            multi_stargate_module::{Itemable, ModuleDb, StargateApplication, StargateUrls},
+            router::RouterWrapper,
+        },
+        storage::interfaces::ItemInterface,
+        traits::IntoBinary,
+    },
+    cosmwasm_schema::cw_serde,
+    cosmwasm_std::{Addr, Api, Binary, BlockInfo, CustomQuery, Querier, Storage},
+    cw_multi_test::{
+        App, AppResponse, Bank, Distribution, Gov, Ibc, Module, Staking, Stargate, Wasm,
+    },
+    osmosis_std::types::osmosis::epochs::v1beta1::{
+        QueryCurrentEpochRequest, QueryCurrentEpochResponse,
+    },
+    prost::Message,
+    rhaki_cw_plus_macro::{urls, Stargate},
+    schemars::JsonSchema,
+    serde::de::DeserializeOwned,
+    std::{cell::RefCell, fmt::Debug, rc::Rc, str::FromStr},
+};
+
+/// Minimal stub of the osmosis `epochs` module.
+///
+/// The epoch is not increased by the block time, use [advance_epoch] to move to the next one
+#[derive(Stargate, Default)]
+#[cw_serde]
+#[stargate(name = "epochs", query_urls = EpochQueryUrls, msgs_urls = EpochMsgUrls)]
+pub struct EpochModule {
+    pub current_epoch: u64,
+}
+
+#[urls]
+pub enum EpochMsgUrls {}
+
+#[urls]
+pub enum EpochQueryUrls {
+    #[strum(serialize = "/osmosis.epochs.v1beta1.Query/CurrentEpoch")]
+    QueryCurrentEpoch,
+}
+
+impl StargateApplication for EpochModule {
+    fn stargate_msg(
+        &mut self,
+        _api: &dyn Api,
+        _storage: Rc<RefCell<&mut dyn Storage>>,
+        _router: &RouterWrapper,
+        _block: &BlockInfo,
+        _sender: Addr,
+        type_url: String,
+        _data: Binary,
+    ) -> AnyResult<AppResponse> {
+        match EpochMsgUrls::from_str(&type_url)? {}
+    }
+
+    fn stargate_query(
+        &self,
+        _api: &dyn Api,
+        _storage: &dyn Storage,
+        _querier: &dyn Querier,
+        _block: &BlockInfo,
+        type_url: String,
+        data: Binary,
+    ) -> AnyResult<Binary> {
+        match EpochQueryUrls::from_str(&type_url)? {
+            EpochQueryUrls::QueryCurrentEpoch => {
+                let msg = QueryCurrentEpochRequest::decode(data.as_slice())?;
+                self.qy_current_epoch(msg)
+            },
+        }
+    }
+}
+
+// Queries
+impl EpochModule {
+    fn qy_current_epoch(&self, _msg: QueryCurrentEpochRequest) -> AnyResult<Binary> {
+        Ok(QueryCurrentEpochResponse {
+            current_epoch: self.current_epoch as i64,
+        }
+        .into_binary()?)
+    }
+}
+
+/// Increase by one the `current_epoch` of the [EpochModule]
+pub fn advance_epoch<
+    BankT,
+    ApiT,
+    StorageT,
+    CustomT,
+    WasmT,
+    StakingT,
+    DistrT,
+    IbcT,
+    GovT,
+    StargateT,
+>(
+    app: &mut App<BankT, ApiT, StorageT, CustomT, WasmT, StakingT, DistrT, IbcT, GovT, StargateT>,
+) -> AnyResult<()>
+where
+    CustomT::ExecT: Debug + PartialEq + Clone + JsonSchema + DeserializeOwned + 'static,
+    CustomT::QueryT: CustomQuery + DeserializeOwned + 'static,
+    WasmT: Wasm<CustomT::ExecT, CustomT::QueryT>,
+    BankT: Bank,
+    ApiT: Api,
+    StorageT: Storage,
+    CustomT: Module,
+    StakingT: Staking,
+    DistrT: Distribution,
+    IbcT: Ibc,
+    GovT: Gov,
+    StargateT: Stargate,
+{
+    EpochModule::use_db(app.storage_mut(), |epoch, _| {
+        epoch.current_epoch += 1;
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        crate::{
+            multi_test::multi_stargate_module::multi_stargate_app,
+            storage::interfaces::ItemInterface,
+        },
+        cosmwasm_std::{Empty, QueryRequest},
+        osmosis_std::types::osmosis::epochs::v1beta1::{
+            QueryCurrentEpochRequest, QueryCurrentEpochResponse,
+        },
+        prost::Message,
+    };
+
+    use super::{advance_epoch, EpochModule};
+
+    #[test]
+    fn test() {
+        let mut app = multi_stargate_app("osmo", vec![Box::new(EpochModule::default())]);
+
+        advance_epoch(&mut app).unwrap();
+        advance_epoch(&mut app).unwrap();
+
+        assert_eq!(EpochModule::load(app.storage()).unwrap().current_epoch, 2);
+
+        let res: QueryCurrentEpochResponse = app
+            .wrap()
+            .query(&QueryRequest::<Empty>::Stargate {
+                path: "/osmosis.epochs.v1beta1.Query/CurrentEpoch".to_string(),
+                data: QueryCurrentEpochRequest {
+                    identifier: "day".to_string(),
+                }
+                .encode_to_vec()
+                .into(),
+            })
+            .unwrap();
+
+        assert_eq!(res.current_epoch, 2);
+    }
+}
@@ -1 +1,2 @@
+pub mod epoch;
 pub mod token_factory;
\ No newline at end of file
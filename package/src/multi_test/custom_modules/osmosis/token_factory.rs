@@ -11,7 +11,8 @@ use {
     anyhow::{anyhow, bail},
     cosmwasm_schema::cw_serde,
     cosmwasm_std::{
-        Addr, Api, BankMsg, Binary, BlockInfo, Coin, CosmosMsg, Empty, Querier, Storage, Uint128,
+        Addr, Api, BankMsg, Binary, BlockInfo, Coin, CosmosMsg, Empty, Event, Querier, Storage,
+        Uint128,
     },
     cw_multi_test::{AppResponse, BankSudo, SudoMsg},
     osmosis_std::types::{
@@ -151,10 +152,20 @@ impl TokenFactoryModule {
 
         self.supplies.insert(denom.clone(), supply);
 
-        router.sudo(SudoMsg::Bank(BankSudo::Mint {
+        let coin = Coin::new(amount.u128(), denom);
+
+        let mut response = router.sudo(SudoMsg::Bank(BankSudo::Mint {
             to_address: to.to_string(),
-            amount: vec![Coin::new(amount.u128(), denom)],
-        }))
+            amount: vec![coin.clone()],
+        }))?;
+
+        response.events.push(
+            Event::new("tf_mint")
+                .add_attribute("mint_to_address", to)
+                .add_attribute("amount", coin.to_string()),
+        );
+
+        Ok(response)
     }
 
     pub fn run_create_denom(
@@ -179,7 +190,7 @@ impl TokenFactoryModule {
         let mut response = if let Some(fee_creation) = &self.fee_creation {
             router
                 .execute(
-                    sender,
+                    sender.clone(),
                     CosmosMsg::<Empty>::Bank(BankMsg::Send {
                         to_address: fee_creation.fee_collector.to_string(),
                         amount: fee_creation.fee.clone(),
@@ -190,6 +201,12 @@ impl TokenFactoryModule {
             AppResponse::default()
         };
 
+        response.events.push(
+            Event::new("create_denom")
+                .add_attribute("denom", &denom)
+                .add_attribute("creator", sender.to_string()),
+        );
+
         response.data = Some(Binary::from(
             MsgCreateDenomResponse {
                 new_token_denom: denom,
@@ -221,12 +238,22 @@ impl TokenFactoryModule {
 
         let burn_from_address = api.addr_validate(&burn_from_address)?;
 
-        router.execute(
-            burn_from_address,
+        let coin = Coin::new(amount.u128(), denom);
+
+        let mut response = router.execute(
+            burn_from_address.clone(),
             CosmosMsg::<Empty>::Bank(BankMsg::Burn {
-                amount: vec![Coin::new(amount.u128(), denom)],
+                amount: vec![coin.clone()],
             }),
-        )
+        )?;
+
+        response.events.push(
+            Event::new("tf_burn")
+                .add_attribute("burn_from_address", burn_from_address)
+                .add_attribute("amount", coin.to_string()),
+        );
+
+        Ok(response)
     }
 
     pub fn run_set_denom_metadata(
@@ -307,12 +334,15 @@ mod test {
                 multi_stargate_module::{multi_stargate_app, ModuleDb},
             },
         },
-        cosmwasm_std::Coin,
+        cosmwasm_std::{Coin, Event},
         cw_multi_test::Executor,
-        osmosis_std::types::osmosis::tokenfactory::v1beta1::MsgCreateDenom,
+        osmosis_std::types::{
+            cosmos::base::v1beta1::Coin as ProtoCoin,
+            osmosis::tokenfactory::v1beta1::{MsgBurn, MsgCreateDenom, MsgMint},
+        },
     };
 
-    use super::{TokenFactoryFee, TokenFactoryModule};
+    use super::{TokenFactoryFee, TokenFactoryModule, TokenFactoryMsgUrls, TokenFactoryQueryUrls};
 
     #[test]
     fn test() {
@@ -343,6 +373,90 @@ mod test {
             AssetInfoPrecisioned::native("uosmo", 6).to_asset(100_u128.into_decimal()),
         );
 
-        app.execute(sender, msg.into()).unwrap();
+        let res = app.execute(sender.clone(), msg.into()).unwrap();
+
+        let denom = format!("factory/{sender}/test");
+
+        assert!(res.has_event(
+            &Event::new("create_denom")
+                .add_attribute("denom", &denom)
+                .add_attribute("creator", sender.to_string())
+        ));
+
+        let res = app
+            .execute(
+                sender.clone(),
+                MsgMint {
+                    sender: sender.to_string(),
+                    amount: Some(ProtoCoin {
+                        denom: denom.clone(),
+                        amount: "1000".to_string(),
+                    }),
+                    mint_to_address: sender.to_string(),
+                }
+                .into(),
+            )
+            .unwrap();
+
+        assert!(res.has_event(
+            &Event::new("tf_mint")
+                .add_attribute("mint_to_address", sender.to_string())
+                .add_attribute("amount", format!("1000{denom}"))
+        ));
+
+        let res = app
+            .execute(
+                sender.clone(),
+                MsgBurn {
+                    sender: sender.to_string(),
+                    amount: Some(ProtoCoin {
+                        denom: denom.clone(),
+                        amount: "400".to_string(),
+                    }),
+                    burn_from_address: sender.to_string(),
+                }
+                .into(),
+            )
+            .unwrap();
+
+        assert!(res.has_event(
+            &Event::new("tf_burn")
+                .add_attribute("burn_from_address", sender.to_string())
+                .add_attribute("amount", format!("400{denom}"))
+        ));
+
+        assert_eq!(
+            app.wrap().query_balance(&sender, &denom).unwrap(),
+            Coin::new(600, denom)
+        );
+    }
+
+    #[test]
+    fn test_urls() {
+        assert_eq!(
+            TokenFactoryMsgUrls::all_urls(),
+            vec![
+                "/osmosis.tokenfactory.v1beta1.MsgMint",
+                "/osmosis.tokenfactory.v1beta1.MsgCreateDenom",
+                "/osmosis.tokenfactory.v1beta1.MsgBurn",
+                "/osmosis.tokenfactory.v1beta1.MsgSetDenomMetadata",
+                "/osmosis.tokenfactory.v1beta1.MsgChangeAdmin",
+            ]
+        );
+
+        assert_eq!(
+            TokenFactoryQueryUrls::all_urls(),
+            vec!["/osmosis.tokenfactory.v1beta1.Query/Params"]
+        );
+
+        assert!(matches!(
+            TokenFactoryMsgUrls::try_from("/osmosis.tokenfactory.v1beta1.MsgBurn".to_string()),
+            Ok(TokenFactoryMsgUrls::MsgBurn)
+        ));
+
+        assert!(TokenFactoryMsgUrls::try_from(
+            "/osmosis.tokenfactory.v1beta1.MsgUnknown".to_string()
+        )
+        .is_err());
     }
 }
@@ -7,7 +7,7 @@ use std::{
 
 use cosmwasm_std::{
     testing::MockStorage, Addr, Api, Binary, Coin, CustomQuery, Deps, DepsMut, Empty, Env,
-    MessageInfo, Reply, Response, StdResult, Storage,
+    MessageInfo, Reply, Response, StdResult, Storage, WasmMsg,
 };
 use cw_multi_test::{
     addons::{MockAddressGenerator, MockApiBech32},
@@ -18,11 +18,12 @@ use cw_multi_test::{
     WasmKeeper,
 };
 use schemars::JsonSchema;
-use serde::de::DeserializeOwned;
+use serde::{de::DeserializeOwned, Serialize};
 
 use crate::{
     asset::{AssetInfoPrecisioned, AssetPrecisioned},
     traits::{IntoAddr, IntoStdResult, Wrapper},
+    wasm::WasmMsgBuilder,
 };
 
 pub use anyhow;
@@ -74,6 +75,8 @@ type QueryFn<T, E, Q> = fn(deps: Deps<Q>, env: Env, msg: T) -> Result<Binary, E>
 
 type ReplyFn<C, E, Q> = fn(deps: DepsMut<Q>, env: Env, msg: Reply) -> Result<Response<C>, E>;
 
+type PermissionedFn<T, C, E, Q> = fn(deps: DepsMut<Q>, env: Env, msg: T) -> Result<Response<C>, E>;
+
 #[allow(clippy::type_complexity)]
 pub fn create_code<
     T1: DeserializeOwned + fmt::Debug + 'static,
@@ -131,6 +134,73 @@ pub fn create_code_with_reply<
     Box::new(contract)
 }
 
+#[allow(clippy::type_complexity)]
+pub fn create_code_with_migrate<
+    T1: DeserializeOwned + fmt::Debug + 'static,
+    T2: DeserializeOwned + 'static,
+    T3: DeserializeOwned + 'static,
+    T4: DeserializeOwned + 'static,
+    C: Clone + fmt::Debug + PartialEq + JsonSchema + 'static,
+    E1: Display + fmt::Debug + Send + Sync + 'static,
+    E2: Display + fmt::Debug + Send + Sync + 'static,
+    E3: Display + fmt::Debug + Send + Sync + 'static,
+    E4: Display + fmt::Debug + Send + Sync + 'static,
+    Q: CustomQuery + DeserializeOwned + 'static,
+>(
+    instantiate: ContractFn<T2, C, E2, Q>,
+    execute: ContractFn<T1, C, E1, Q>,
+    query: QueryFn<T3, E3, Q>,
+    migrate: PermissionedFn<T4, C, E4, Q>,
+) -> Box<
+    ContractWrapper<
+        T1,
+        T2,
+        T3,
+        E1,
+        E2,
+        E3,
+        C,
+        Q,
+        cosmwasm_std::Empty,
+        anyhow::Error,
+        anyhow::Error,
+        T4,
+        E4,
+    >,
+> {
+    let contract: ContractWrapper<T1, T2, T3, E1, E2, E3, C, Q> =
+        ContractWrapper::new(execute, instantiate, query);
+
+    Box::new(contract.with_migrate(migrate))
+}
+
+#[allow(clippy::type_complexity)]
+pub fn create_code_with_migrate_and_sudo<
+    T1: DeserializeOwned + fmt::Debug + 'static,
+    T2: DeserializeOwned + 'static,
+    T3: DeserializeOwned + 'static,
+    T4: DeserializeOwned + 'static,
+    T5: DeserializeOwned + 'static,
+    C: Clone + fmt::Debug + PartialEq + JsonSchema + 'static,
+    E1: Display + fmt::Debug + Send + Sync + 'static,
+    E2: Display + fmt::Debug + Send + Sync + 'static,
+    E3: Display + fmt::Debug + Send + Sync + 'static,
+    E4: Display + fmt::Debug + Send + Sync + 'static,
+    E5: Display + fmt::Debug + Send + Sync + 'static,
+    Q: CustomQuery + DeserializeOwned + 'static,
+>(
+    instantiate: ContractFn<T2, C, E2, Q>,
+    execute: ContractFn<T1, C, E1, Q>,
+    query: QueryFn<T3, E3, Q>,
+    migrate: PermissionedFn<T4, C, E4, Q>,
+    sudo: PermissionedFn<T5, C, E5, Q>,
+) -> Box<ContractWrapper<T1, T2, T3, E1, E2, E3, C, Q, T5, E5, anyhow::Error, T4, E4>> {
+    let contract: ContractWrapper<T1, T2, T3, E1, E2, E3, C, Q> =
+        ContractWrapper::new(execute, instantiate, query);
+
+    Box::new(contract.with_migrate(migrate).with_sudo(sudo))
+}
+
 pub trait UnwrapError {
     type Error;
     fn unwrap_err_contains(self, text: impl Into<String>) -> Self::Error;
@@ -163,6 +233,16 @@ pub trait AppExt {
         address: &Addr,
         asset: &AssetInfoPrecisioned,
     ) -> StdResult<AssetPrecisioned>;
+    /// Same as `cw_multi_test::Executor::execute_contract`, without requiring to import `Executor`.
+    ///
+    /// If both traits are in scope, the call has to be disambiguated (ex: `AppExt::execute_contract(&mut app, ..)`)
+    fn execute_contract<T: Serialize + Debug>(
+        &mut self,
+        sender: Addr,
+        contract_addr: Addr,
+        msg: &T,
+        send_funds: &[Coin],
+    ) -> AppResult;
 }
 
 pub trait Bench32AppExt {
@@ -207,7 +287,8 @@ where
                     .unwrap()
                     .minter;
 
-                self.execute_contract(
+                Executor::execute_contract(
+                    self,
                     minter.into_unchecked_addr(),
                     cw20.clone(),
                     &cw20::Cw20ExecuteMsg::Mint {
@@ -251,6 +332,20 @@ where
             _ => todo!(),
         }
     }
+
+    fn execute_contract<T: Serialize + Debug>(
+        &mut self,
+        sender: Addr,
+        contract_addr: Addr,
+        msg: &T,
+        send_funds: &[Coin],
+    ) -> AppResult {
+        Executor::execute(
+            self,
+            sender,
+            WasmMsg::build_execute(contract_addr, msg, send_funds.to_vec())?.into(),
+        )
+    }
 }
 
 impl<BankT, StorageT, CustomT, WasmT, StakingT, DistrT, IbcT, GovT, StargateT> Bench32AppExt
@@ -304,3 +399,157 @@ fn test() {
     let b = "10.1".into_decimal();
     assert_with_tollerance(a, b, "2".into_decimal());
 }
+
+#[test]
+fn test_execute_contract() {
+    use cosmwasm_std::{to_json_binary, Event};
+    use cw_storage_plus::Item;
+
+    const COUNTER: Item<u64> = Item::new("counter");
+
+    fn instantiate(
+        deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        _msg: Empty,
+    ) -> StdResult<Response> {
+        COUNTER.save(deps.storage, &0)?;
+        Ok(Response::new())
+    }
+
+    fn execute(deps: DepsMut, _env: Env, info: MessageInfo, msg: u64) -> StdResult<Response> {
+        COUNTER.update(deps.storage, |counter| StdResult::Ok(counter + msg))?;
+        Ok(Response::new().add_attribute("sender", info.sender))
+    }
+
+    fn query(deps: Deps, _env: Env, _msg: Empty) -> StdResult<Binary> {
+        to_json_binary(&COUNTER.load(deps.storage)?)
+    }
+
+    let mut app = build_bech32_app("osmo");
+
+    let sender = app.generate_addr("sender");
+
+    let uosmo = AssetInfoPrecisioned::native("uosmo", 6);
+
+    app.mint(sender.clone(), uosmo.to_asset(100_u128));
+
+    let code_id = app.store_code(create_code(instantiate, execute, query));
+
+    let contract = app
+        .instantiate_contract(code_id, sender.clone(), &Empty {}, &[], "counter", None)
+        .unwrap();
+
+    // `Executor` is in scope too, so the call has to be disambiguated
+    let res = AppExt::execute_contract(
+        &mut app,
+        sender.clone(),
+        contract.clone(),
+        &5_u64,
+        &[Coin::new(100, "uosmo")],
+    )
+    .unwrap();
+
+    assert!(res.has_event(&Event::new("wasm").add_attribute("sender", sender.to_string())));
+
+    assert_eq!(
+        app.wrap()
+            .query_wasm_smart::<u64>(&contract, &Empty {})
+            .unwrap(),
+        5
+    );
+
+    assert_eq!(
+        app.qy_balance(&contract, &uosmo).unwrap(),
+        uosmo.to_asset(100_u128)
+    );
+}
+
+#[test]
+fn test_migrate_and_sudo() {
+    use cosmwasm_std::to_json_binary;
+    use cw_storage_plus::Item;
+
+    const COUNTER: Item<u64> = Item::new("counter");
+
+    fn instantiate(
+        deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        _msg: Empty,
+    ) -> StdResult<Response> {
+        COUNTER.save(deps.storage, &0)?;
+        Ok(Response::new())
+    }
+
+    fn execute(_deps: DepsMut, _env: Env, _info: MessageInfo, _msg: Empty) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    fn query(deps: Deps, _env: Env, _msg: Empty) -> StdResult<Binary> {
+        to_json_binary(&COUNTER.load(deps.storage)?)
+    }
+
+    fn migrate(deps: DepsMut, _env: Env, msg: u64) -> StdResult<Response> {
+        COUNTER.save(deps.storage, &msg)?;
+        Ok(Response::new())
+    }
+
+    fn sudo(deps: DepsMut, _env: Env, msg: u64) -> StdResult<Response> {
+        COUNTER.update(deps.storage, |counter| StdResult::Ok(counter + msg))?;
+        Ok(Response::new())
+    }
+
+    let mut app = build_bech32_app("osmo");
+
+    let admin = app.generate_addr("admin");
+
+    let code_v1 = app.store_code(create_code_with_migrate(
+        instantiate,
+        execute,
+        query,
+        migrate,
+    ));
+
+    let code_v2 = app.store_code(create_code_with_migrate_and_sudo(
+        instantiate,
+        execute,
+        query,
+        migrate,
+        sudo,
+    ));
+
+    let contract = app
+        .instantiate_contract(
+            code_v1,
+            admin.clone(),
+            &Empty {},
+            &[],
+            "counter",
+            Some(admin.to_string()),
+        )
+        .unwrap();
+
+    // `code_v1` has no sudo entry point
+    app.wasm_sudo(contract.clone(), &1_u64).unwrap_err();
+
+    app.migrate_contract(admin.clone(), contract.clone(), &10_u64, code_v2)
+        .unwrap();
+
+    let counter = |app: &Bech32App| {
+        app.wrap()
+            .query_wasm_smart::<u64>(&contract, &Empty {})
+            .unwrap()
+    };
+
+    assert_eq!(counter(&app), 10);
+
+    app.wasm_sudo(contract.clone(), &5_u64).unwrap();
+
+    assert_eq!(counter(&app), 15);
+
+    app.migrate_contract(admin.clone(), contract.clone(), &20_u64, code_v1)
+        .unwrap();
+
+    assert_eq!(counter(&app), 20);
+}
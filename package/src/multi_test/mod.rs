@@ -2,3 +2,4 @@ pub mod custom_modules;
 pub mod helper;
 pub mod router;
 pub mod multi_stargate_module;
+pub mod scheduled_app;
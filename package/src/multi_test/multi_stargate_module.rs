@@ -2,11 +2,11 @@ use std::{cell::RefCell, collections::BTreeMap, rc::Rc};
 
 use anyhow::bail;
 use cosmwasm_std::{
-    testing::MockStorage, Addr, Api, Binary, BlockInfo, CustomQuery, Querier, Storage,
+    testing::MockStorage, Addr, Api, Binary, BlockInfo, CustomQuery, Empty, Querier, Storage,
 };
 use cw_multi_test::{
     addons::MockApiBech32, no_init, App, AppBuilder, AppResponse, BankKeeper, CosmosRouter,
-    DistributionKeeper, GovFailingModule, IbcFailingModule, StakeKeeper, Stargate,
+    DistributionKeeper, GovFailingModule, IbcFailingModule, StakeKeeper, Stargate, Wasm,
 };
 use schemars::JsonSchema;
 use serde::de::DeserializeOwned;
@@ -41,6 +41,39 @@ pub fn multi_stargate_app(
     multi_stargate_app_builder(prefix, apps).build(no_init)
 }
 
+/// Same as [multi_stargate_app] but with a custom `Wasm` keeper instead of [DefaultWasmKeeper].
+///
+/// [multi_stargate_app_builder] doesn't set an address generator on the `WasmKeeper` (as [build_bech32_app](super::helper::build_bech32_app) does),
+/// so contracts that use `WasmMsg::Instantiate2` should be tested with a keeper that provides a `MockAddressGenerator`:
+///
+/// ```ignore
+/// let app = multi_stargate_app_with_wasm(
+///     "osmo",
+///     vec![Box::new(TokenFactoryModule::default())],
+///     WasmKeeper::default().with_address_generator(MockAddressGenerator),
+/// );
+/// ```
+pub fn multi_stargate_app_with_wasm<W: Wasm<Empty, Empty>>(
+    prefix: &'static str,
+    apps: Vec<Box<dyn StargateApplication + 'static>>,
+    wasm: W,
+) -> App<
+    BankKeeper,
+    MockApiBech32,
+    MockStorage,
+    FailingCustom,
+    W,
+    StakeKeeper,
+    DistributionKeeper,
+    IbcFailingModule,
+    GovFailingModule,
+    MultiStargateModule,
+> {
+    multi_stargate_app_builder(prefix, apps)
+        .with_wasm(wasm)
+        .build(no_init)
+}
+
 pub fn multi_stargate_app_builder(
     prefix: &'static str,
     apps: Vec<Box<dyn StargateApplication + 'static>>,
@@ -234,3 +267,105 @@ pub trait ModuleDb: Default + ItemInterface {
 }
 
 impl<T> ModuleDb for T where T: ItemInterface + Default {}
+
+#[cfg(test)]
+mod test {
+    use {
+        crate::{
+            multi_test::helper::{create_code, Bench32AppExt},
+            wasm::WasmMsgBuilder,
+        },
+        cosmwasm_std::{
+            instantiate2_address, Api, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Response,
+            StdResult, WasmMsg,
+        },
+        cw_multi_test::{addons::MockAddressGenerator, Executor, WasmKeeper},
+    };
+
+    use super::multi_stargate_app_with_wasm;
+
+    fn instantiate(
+        _deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        _msg: Empty,
+    ) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    fn execute(_deps: DepsMut, _env: Env, _info: MessageInfo, _msg: Empty) -> StdResult<Response> {
+        Ok(Response::new())
+    }
+
+    fn query(_deps: Deps, _env: Env, _msg: Empty) -> StdResult<Binary> {
+        Ok(Binary::default())
+    }
+
+    #[test]
+    fn test_with_wasm() {
+        let mut app = multi_stargate_app_with_wasm(
+            "osmo",
+            vec![],
+            WasmKeeper::default().with_address_generator(MockAddressGenerator),
+        );
+
+        let sender = app.generate_addr("sender");
+
+        let code_id = app.store_code(create_code(instantiate, execute, query));
+
+        let contract = app
+            .instantiate_contract(code_id, sender.clone(), &Empty {}, &[], "contract", None)
+            .unwrap();
+
+        assert!(contract.as_str().starts_with("osmo1"));
+
+        let salt = Binary::from(b"salt");
+
+        let res = app
+            .execute(
+                sender.clone(),
+                WasmMsg::build_init2(
+                    None,
+                    code_id,
+                    Empty {},
+                    vec![],
+                    "contract_2".to_string(),
+                    salt.clone(),
+                )
+                .unwrap()
+                .into(),
+            )
+            .unwrap();
+
+        let contract_2 = res
+            .events
+            .iter()
+            .find(|event| event.ty == "instantiate")
+            .and_then(|event| {
+                event
+                    .attributes
+                    .iter()
+                    .find(|attribute| attribute.key == "_contract_address")
+            })
+            .unwrap()
+            .value
+            .clone();
+
+        let checksum = app.wrap().query_wasm_code_info(code_id).unwrap().checksum;
+
+        let predicted = app
+            .api()
+            .addr_humanize(
+                &instantiate2_address(
+                    checksum.as_slice(),
+                    &app.api().addr_canonicalize(sender.as_str()).unwrap(),
+                    &salt,
+                )
+                .unwrap(),
+            )
+            .unwrap();
+
+        assert!(contract_2.starts_with("osmo1"));
+        assert_eq!(contract_2, predicted.to_string());
+    }
+}
@@ -0,0 +1,213 @@
+use std::{
+    fmt::Debug,
+    ops::{Deref, DerefMut},
+};
+
+use cosmwasm_std::{Addr, Api, CosmosMsg, CustomQuery, Empty, Storage, Timestamp};
+use cw_multi_test::{
+    error::AnyResult, App, AppResponse, Bank, Distribution, Executor, Gov, Ibc, Module, Staking,
+    Stargate, Wasm,
+};
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
+
+pub struct ScheduledEvent<C = Empty> {
+    pub at_time: Timestamp,
+    pub msg: CosmosMsg<C>,
+    pub sender: Addr,
+}
+
+/// Wrapper of `App` that execute `CosmosMsg` once the block time reach the scheduled time.
+///
+/// `ScheduledApp` deref into the inner `App`, so all the `App` methods can be used directly (ex: `increase_time` from [AppExt](super::helper::AppExt))
+pub struct ScheduledApp<A, C = Empty> {
+    pub app: A,
+    scheduled: Vec<ScheduledEvent<C>>,
+}
+
+impl<A, C> ScheduledApp<A, C> {
+    pub fn new(app: A) -> Self {
+        Self {
+            app,
+            scheduled: vec![],
+        }
+    }
+
+    /// Schedule `msg` to be executed by `sender` once the block time reach `at_time`
+    pub fn schedule_event(&mut self, at_time: Timestamp, msg: CosmosMsg<C>, sender: Addr) {
+        let index = self
+            .scheduled
+            .partition_point(|event| event.at_time <= at_time);
+
+        self.scheduled.insert(
+            index,
+            ScheduledEvent {
+                at_time,
+                msg,
+                sender,
+            },
+        );
+    }
+
+    /// Events not yet executed, ordered by `at_time`
+    pub fn scheduled_events(&self) -> &[ScheduledEvent<C>] {
+        &self.scheduled
+    }
+}
+
+impl<BankT, ApiT, StorageT, CustomT, WasmT, StakingT, DistrT, IbcT, GovT, StargateT>
+    ScheduledApp<
+        App<BankT, ApiT, StorageT, CustomT, WasmT, StakingT, DistrT, IbcT, GovT, StargateT>,
+        CustomT::ExecT,
+    >
+where
+    CustomT::ExecT: Debug + PartialEq + Clone + JsonSchema + DeserializeOwned + 'static,
+    CustomT::QueryT: CustomQuery + DeserializeOwned + 'static,
+    WasmT: Wasm<CustomT::ExecT, CustomT::QueryT>,
+    BankT: Bank,
+    ApiT: Api,
+    StorageT: Storage,
+    CustomT: Module,
+    StakingT: Staking,
+    DistrT: Distribution,
+    IbcT: Ibc,
+    GovT: Gov,
+    StargateT: Stargate,
+{
+    /// Execute, ordered by `at_time`, all the scheduled events with `at_time` lower or equal to the current block time.
+    ///
+    /// Events are removed only once executed successfully. If an execution fails, the error is returned:
+    /// the failed event and the following ones are kept, while the events already executed in this call are removed
+    /// and their responses are discarded
+    pub fn process_scheduled_events(&mut self) -> AnyResult<Vec<AppResponse>> {
+        let now = self.app.block_info().time;
+
+        let mut responses = vec![];
+
+        while let Some(event) = self.scheduled.first().filter(|event| event.at_time <= now) {
+            responses.push(self.app.execute(event.sender.clone(), event.msg.clone())?);
+            self.scheduled.remove(0);
+        }
+
+        Ok(responses)
+    }
+}
+
+impl<A, C> Deref for ScheduledApp<A, C> {
+    type Target = A;
+
+    fn deref(&self) -> &Self::Target {
+        &self.app
+    }
+}
+
+impl<A, C> DerefMut for ScheduledApp<A, C> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.app
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use cosmwasm_std::{BankMsg, Coin};
+
+    use crate::{
+        asset::AssetInfoPrecisioned,
+        multi_test::helper::{build_bech32_app, AppExt, Bench32AppExt},
+    };
+
+    use super::ScheduledApp;
+
+    #[test]
+    fn test() {
+        let mut app: ScheduledApp<_> = ScheduledApp::new(build_bech32_app("osmo"));
+
+        let sender = app.generate_addr("sender");
+        let receiver = app.generate_addr("receiver");
+
+        let uosmo = AssetInfoPrecisioned::native("uosmo", 6);
+
+        app.mint(sender.clone(), uosmo.to_asset(300_u128));
+
+        let now = app.block_info().time;
+
+        for (seconds, amount) in [(200, 200), (100, 100)] {
+            app.schedule_event(
+                now.plus_seconds(seconds),
+                BankMsg::Send {
+                    to_address: receiver.to_string(),
+                    amount: vec![Coin::new(amount, "uosmo")],
+                }
+                .into(),
+                sender.clone(),
+            );
+        }
+
+        assert!(app.process_scheduled_events().unwrap().is_empty());
+
+        app.increase_time(100);
+
+        assert_eq!(app.process_scheduled_events().unwrap().len(), 1);
+        assert_eq!(app.scheduled_events().len(), 1);
+        assert_eq!(
+            app.qy_balance(&receiver, &uosmo).unwrap(),
+            uosmo.to_asset(100_u128)
+        );
+
+        app.increase_time(100);
+
+        assert_eq!(app.process_scheduled_events().unwrap().len(), 1);
+        assert!(app.scheduled_events().is_empty());
+        assert_eq!(
+            app.qy_balance(&receiver, &uosmo).unwrap(),
+            uosmo.to_asset(300_u128)
+        );
+    }
+
+    #[test]
+    fn test_failed_event() {
+        let mut app: ScheduledApp<_> = ScheduledApp::new(build_bech32_app("osmo"));
+
+        let sender = app.generate_addr("sender");
+        let receiver = app.generate_addr("receiver");
+
+        let uosmo = AssetInfoPrecisioned::native("uosmo", 6);
+
+        app.mint(sender.clone(), uosmo.to_asset(100_u128));
+
+        let now = app.block_info().time;
+
+        for (seconds, amount) in [(100, 100), (200, 200), (300, 100)] {
+            app.schedule_event(
+                now.plus_seconds(seconds),
+                BankMsg::Send {
+                    to_address: receiver.to_string(),
+                    amount: vec![Coin::new(amount, "uosmo")],
+                }
+                .into(),
+                sender.clone(),
+            );
+        }
+
+        app.increase_time(300);
+
+        // The second event fails for insufficient funds
+        app.process_scheduled_events().unwrap_err();
+
+        assert_eq!(app.scheduled_events().len(), 2);
+        assert_eq!(app.scheduled_events()[0].at_time, now.plus_seconds(200));
+        assert_eq!(
+            app.qy_balance(&receiver, &uosmo).unwrap(),
+            uosmo.to_asset(100_u128)
+        );
+
+        app.mint(sender.clone(), uosmo.to_asset(300_u128));
+
+        assert_eq!(app.process_scheduled_events().unwrap().len(), 2);
+        assert!(app.scheduled_events().is_empty());
+        assert_eq!(
+            app.qy_balance(&receiver, &uosmo).unwrap(),
+            uosmo.to_asset(400_u128)
+        );
+    }
+}
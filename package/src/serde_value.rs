@@ -6,7 +6,11 @@ use {
     cosmwasm_schema::cw_serde,
     cosmwasm_std::{CosmosMsg, StdError, StdResult},
     serde::{de::DeserializeOwned, Deserialize, Serialize},
-    std::{collections::HashMap, fmt::Debug, hash::Hash},
+    std::{
+        collections::{BTreeMap, HashMap},
+        fmt::Debug,
+        hash::Hash,
+    },
 };
 
 pub use {
@@ -55,6 +59,38 @@ pub fn value_to_comsos_msg(value: &Value) -> StdResult<CosmosMsg> {
     value.clone().deserialize_into().into_std_result()
 }
 
+/// Apply `patch` on `target` following the JSON merge-patch semantics (RFC 7396).
+///
+/// - If `patch` is not a `Map`, `target` is replaced by `patch`;
+/// - Otherwise, each key of `patch` with a `Value::Unit` (or `Value::Option(None)`) is removed from `target`,
+///   while the other keys are recursively patched.
+pub fn value_patch(target: &mut Value, patch: &Value) -> StdResult<()> {
+    let patch_map = match patch {
+        Value::Map(patch_map) => patch_map,
+        _ => {
+            *target = patch.clone();
+            return Ok(());
+        },
+    };
+
+    if !matches!(target, Value::Map(_)) {
+        *target = Value::Map(BTreeMap::new());
+    }
+
+    if let Value::Map(target_map) = target {
+        for (key, value) in patch_map {
+            match value {
+                Value::Unit | Value::Option(None) => {
+                    target_map.remove(key);
+                },
+                _ => value_patch(target_map.entry(key.clone()).or_insert(Value::Unit), value)?,
+            }
+        }
+    }
+
+    Ok(())
+}
+
 pub trait SerdeValue {
     fn from_b64(encoded_b64: impl Into<String>) -> StdResult<Value>;
     fn from_string(string: impl Into<String>) -> StdResult<Value>;
@@ -35,6 +35,60 @@ pub mod map {
             .map(|item| item.unwrap())
             .collect())
     }
+
+    /// Load the smallest key of a `cw_storage_plus::Map`. Return `None` if the map is empty
+    pub fn min_key<
+        'a,
+        K: PrimaryKey<'a> + KeyDeserialize + 'static,
+        T: Serialize + DeserializeOwned,
+    >(
+        storage: &dyn Storage,
+        map: &Map<'a, K, T>,
+    ) -> Option<K::Output> {
+        first_key(storage, map, Order::Ascending)
+    }
+
+    /// Load the greatest key of a `cw_storage_plus::Map`. Return `None` if the map is empty
+    pub fn max_key<
+        'a,
+        K: PrimaryKey<'a> + KeyDeserialize + 'static,
+        T: Serialize + DeserializeOwned,
+    >(
+        storage: &dyn Storage,
+        map: &Map<'a, K, T>,
+    ) -> Option<K::Output> {
+        first_key(storage, map, Order::Descending)
+    }
+
+    /// Load both `(min_key, max_key)` of a `cw_storage_plus::Map`. Return `None` if the map is empty.
+    ///
+    /// Run two bounded queries, one `Order::Ascending` and one `Order::Descending`, each reading only the first key
+    pub fn key_bounds<
+        'a,
+        K: PrimaryKey<'a> + KeyDeserialize + 'static,
+        T: Serialize + DeserializeOwned,
+    >(
+        storage: &dyn Storage,
+        map: &Map<'a, K, T>,
+    ) -> Option<(K::Output, K::Output)> {
+        let min = min_key(storage, map)?;
+        let max = max_key(storage, map)?;
+        Some((min, max))
+    }
+
+    fn first_key<
+        'a,
+        K: PrimaryKey<'a> + KeyDeserialize + 'static,
+        T: Serialize + DeserializeOwned,
+    >(
+        storage: &dyn Storage,
+        map: &Map<'a, K, T>,
+        order: Order,
+    ) -> Option<K::Output> {
+        map.keys(storage, None, None, order)
+            .next()
+            .map(|key| key.unwrap())
+    }
 }
 
 pub mod multi_index {
@@ -267,6 +321,105 @@ pub mod interfaces {
     }
 }
 
+pub mod versioned {
+    use cosmwasm_std::{from_json, to_json_vec, StdError, StdResult, Storage};
+    use serde::{de::DeserializeOwned, Serialize};
+
+    use super::interfaces::ItemInterface;
+
+    const VERSION_MARKER: u8 = 0x00;
+
+    /// Wrapper of an [ItemInterface] that save the schema version `V` before the payload,
+    /// prefixed by a marker byte (`0x00`) that can not be the first byte of a json.
+    ///
+    /// The value is stored under `T::NAMESPACE`, replacing the one saved with [ItemInterface::save]
+    #[derive(Debug)]
+    pub struct VersionedItem<T, const V: u8>(pub T);
+
+    impl<T: ItemInterface, const V: u8> VersionedItem<T, V> {
+        pub const VERSION: u8 = V;
+
+        pub fn new(value: T) -> Self {
+            Self(value)
+        }
+
+        /// Load the value, returning `StdError` if it was saved with a version different from `V`
+        pub fn load(storage: &dyn Storage) -> StdResult<Self> {
+            let (version, value) = load_with_version(storage, T::NAMESPACE)?;
+
+            if version != V {
+                return Err(StdError::generic_err(format!(
+                    "Item {} on contract {} has version {}, expected: {}",
+                    T::NAMESPACE,
+                    T::CONTRACT_NAME,
+                    version,
+                    V
+                )));
+            }
+
+            Ok(Self(value))
+        }
+
+        pub fn save(&self, storage: &mut dyn Storage) -> StdResult<()> {
+            save_with_version(storage, T::NAMESPACE, V, &self.0)
+        }
+
+        pub fn into_inner(self) -> T {
+            self.0
+        }
+    }
+
+    /// Load a value saved with a version prefix, returning `(version, value)`.
+    ///
+    /// Return `StdError` if the value has been saved without version (ex: with [ItemInterface::save])
+    pub fn load_with_version<T: DeserializeOwned>(
+        storage: &dyn Storage,
+        namespace: &str,
+    ) -> StdResult<(u8, T)> {
+        let data = storage
+            .get(namespace.as_bytes())
+            .ok_or(StdError::not_found(format!("Versioned item {namespace}")))?;
+
+        match data.as_slice() {
+            [VERSION_MARKER, version, payload @ ..] => Ok((*version, from_json(payload)?)),
+            _ => Err(StdError::generic_err(format!(
+                "Item {namespace} is not versioned"
+            ))),
+        }
+    }
+
+    /// Load the value saved under `namespace` as `Old`, map it with `f` and save it as `New`, increasing the version by one.
+    ///
+    /// Return the new version
+    pub fn migrate_item<Old: DeserializeOwned, New: Serialize, F: FnOnce(Old) -> New>(
+        storage: &mut dyn Storage,
+        namespace: &str,
+        f: F,
+    ) -> StdResult<u8> {
+        let (version, old) = load_with_version::<Old>(storage, namespace)?;
+
+        let new_version = version.checked_add(1).ok_or(StdError::generic_err(format!(
+            "Versioned item {namespace} reached max version"
+        )))?;
+
+        save_with_version(storage, namespace, new_version, &f(old))?;
+
+        Ok(new_version)
+    }
+
+    fn save_with_version<T: Serialize>(
+        storage: &mut dyn Storage,
+        namespace: &str,
+        version: u8,
+        value: &T,
+    ) -> StdResult<()> {
+        let mut data = vec![VERSION_MARKER, version];
+        data.extend(to_json_vec(value)?);
+        storage.set(namespace.as_bytes(), &data);
+        Ok(())
+    }
+}
+
 fn min_max_from_order<'a, PK: PrimaryKey<'a> + KeyDeserialize + 'static>(
     start_after: Option<PK>,
     order: &Order,
@@ -298,7 +451,10 @@ mod test {
     use cosmwasm_std::{testing::mock_dependencies, Order, StdError, StdResult};
     use cw_storage_plus::Map;
 
-    use crate::storage::interfaces::MapExt;
+    use crate::storage::{
+        interfaces::MapExt,
+        versioned::{load_with_version, migrate_item, VersionedItem},
+    };
 
     use super::interfaces::ItemInterface;
 
@@ -381,4 +537,92 @@ mod test {
             )
         )
     }
+
+    #[cw_serde]
+    pub struct TestConfigV2 {
+        some_value: String,
+        another_value: u64,
+        new_value: bool,
+    }
+
+    impl ItemInterface for TestConfigV2 {
+        const NAMESPACE: &'static str = "config";
+        const CONTRACT_NAME: &'static str = "test_contract";
+    }
+
+    #[test]
+    fn test_versioned_item() {
+        let mut deps = mock_dependencies();
+
+        let config = TestConfig {
+            some_value: "foo".to_string(),
+            another_value: 1,
+        };
+
+        VersionedItem::<_, 1>::new(config.clone())
+            .save(deps.as_mut().storage)
+            .unwrap();
+
+        let (version, loaded) =
+            load_with_version::<TestConfig>(deps.as_ref().storage, TestConfig::NAMESPACE).unwrap();
+
+        assert_eq!(version, 1);
+        assert_eq!(loaded, config);
+
+        let new_version = migrate_item(
+            deps.as_mut().storage,
+            TestConfig::NAMESPACE,
+            |old: TestConfig| TestConfigV2 {
+                some_value: old.some_value,
+                another_value: old.another_value,
+                new_value: true,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(new_version, 2);
+
+        VersionedItem::<TestConfig, 1>::load(deps.as_ref().storage).unwrap_err();
+
+        let loaded = VersionedItem::<TestConfigV2, 2>::load(deps.as_ref().storage)
+            .unwrap()
+            .into_inner();
+
+        assert_eq!(
+            loaded,
+            TestConfigV2 {
+                some_value: "foo".to_string(),
+                another_value: 1,
+                new_value: true,
+            }
+        );
+    }
+
+    #[test]
+    fn test_unversioned_item() {
+        let mut deps = mock_dependencies();
+
+        TestConfig {
+            some_value: "foo".to_string(),
+            another_value: 1,
+        }
+        .save(deps.as_mut().storage)
+        .unwrap();
+
+        let err = load_with_version::<TestConfig>(deps.as_ref().storage, TestConfig::NAMESPACE)
+            .unwrap_err();
+
+        assert_eq!(err, StdError::generic_err("Item config is not versioned"));
+
+        VersionedItem::<TestConfig, 1>::load(deps.as_ref().storage).unwrap_err();
+
+        migrate_item(
+            deps.as_mut().storage,
+            TestConfig::NAMESPACE,
+            |old: TestConfig| old,
+        )
+        .unwrap_err();
+
+        TestConfig::load(deps.as_ref().storage).unwrap();
+    }
 }
@@ -11,6 +11,18 @@ pub trait IntoAddr: Into<String> + Clone {
     fn into_unchecked_addr(&self) -> Addr {
         Addr::unchecked(self.clone())
     }
+    /// Validate the address with `api.addr_validate` and assert its bech32 prefix is exactly `expected_prefix`
+    fn into_addr_with_prefix(self, api: &dyn Api, expected_prefix: &str) -> StdResult<Addr> {
+        let addr = self.into_addr(api)?;
+
+        if !addr.as_str().starts_with(&format!("{expected_prefix}1")) {
+            return Err(StdError::generic_err(format!(
+                "Invalid address prefix for {addr}, expected: {expected_prefix}"
+            )));
+        }
+
+        Ok(addr)
+    }
 }
 
 impl<T> IntoAddr for T where T: Into<String> + Clone {}
@@ -133,11 +145,60 @@ pub trait Wrapper: Sized {
 
 impl<T> Wrapper for T {}
 
+pub trait VecExt<T>: Sized {
+    /// Consume the `Vec` and return the first element, if any
+    fn first_ok(self) -> Option<T>;
+
+    /// Consume the `Vec` and return the first element mapped to `Some` by `f`
+    fn find_map_first<U, F: Fn(T) -> Option<U>>(self, f: F) -> Option<U>;
+
+    /// Split the `Vec` at the first element that match `pred`.
+    ///
+    /// The matching element is the first one of the second `Vec`.
+    /// If no element match, the second `Vec` is empty
+    fn split_at_first<F: Fn(&T) -> bool>(self, pred: F) -> (Vec<T>, Vec<T>);
+
+    /// Map each element with `f`, returning the first error found without any partial result
+    fn try_map<U, E, F: Fn(T) -> Result<U, E>>(self, f: F) -> Result<Vec<U>, E>;
+}
+
+impl<T> VecExt<T> for Vec<T> {
+    fn first_ok(self) -> Option<T> {
+        self.into_iter().next()
+    }
+
+    fn find_map_first<U, F: Fn(T) -> Option<U>>(self, f: F) -> Option<U> {
+        self.into_iter().find_map(f)
+    }
+
+    fn split_at_first<F: Fn(&T) -> bool>(mut self, pred: F) -> (Vec<T>, Vec<T>) {
+        match self.iter().position(pred) {
+            Some(index) => {
+                let second = self.split_off(index);
+                (self, second)
+            },
+            None => (self, vec![]),
+        }
+    }
+
+    fn try_map<U, E, F: Fn(T) -> Result<U, E>>(self, f: F) -> Result<Vec<U>, E> {
+        let mut res = Vec::with_capacity(self.len());
+
+        for val in self {
+            res.push(f(val)?);
+        }
+
+        Ok(res)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use cosmwasm_std::{testing::mock_dependencies, Addr, Coin, StdError};
 
-    use crate::traits::{FromBinary, FromBinaryResult, IntoAddr, IntoBinary, IntoBinaryResult};
+    use crate::traits::{
+        FromBinary, FromBinaryResult, IntoAddr, IntoBinary, IntoBinaryResult, VecExt,
+    };
 
     #[test]
     fn test() {
@@ -172,5 +233,59 @@ mod test {
             Addr::unchecked("terra123"),
             "terra123".to_string().into_addr(&deps.api).unwrap()
         );
+
+        assert_eq!(
+            Addr::unchecked("terra123"),
+            "terra123"
+                .into_addr_with_prefix(&deps.api, "terra")
+                .unwrap()
+        );
+
+        "terra123"
+            .into_addr_with_prefix(&deps.api, "osmo")
+            .unwrap_err();
+
+        assert_eq!(
+            Addr::unchecked("osmovaloper123"),
+            "osmovaloper123"
+                .into_addr_with_prefix(&deps.api, "osmovaloper")
+                .unwrap()
+        );
+
+        "osmovaloper123"
+            .into_addr_with_prefix(&deps.api, "osmo")
+            .unwrap_err();
+    }
+
+    #[test]
+    fn test_vec_ext() {
+        assert_eq!(vec![1, 2, 3].first_ok(), Some(1));
+        assert_eq!(Vec::<u64>::new().first_ok(), None);
+
+        assert_eq!(
+            vec!["a", "1", "2"].find_map_first(|val| val.parse::<u64>().ok()),
+            Some(1)
+        );
+        assert_eq!(
+            vec!["a", "b"].find_map_first(|val| val.parse::<u64>().ok()),
+            None
+        );
+
+        assert_eq!(
+            vec![1, 2, 3, 4].split_at_first(|val| *val > 2),
+            (vec![1, 2], vec![3, 4])
+        );
+        assert_eq!(
+            vec![1, 2].split_at_first(|val| *val > 2),
+            (vec![1, 2], vec![])
+        );
+
+        assert_eq!(
+            vec!["1", "2"].try_map(|val| val.parse::<u64>()),
+            Ok(vec![1, 2])
+        );
+        assert!(vec!["1", "a", "2"]
+            .try_map(|val| val.parse::<u64>())
+            .is_err());
     }
 }
@@ -1,7 +1,11 @@
 use {
     cosmwasm_schema::cw_serde,
     cosmwasm_std::{StdError, StdResult},
-    std::{collections::HashMap, fmt::Debug, hash::Hash},
+    std::{
+        collections::{BTreeMap, HashMap},
+        fmt::Debug,
+        hash::Hash,
+    },
 };
 
 /// Transform a `Vec<T>` into `HashMap<uszie, T>`, where `key` is the index of `T`
@@ -15,6 +19,35 @@ pub fn vec_to_i_hashmap<T>(vec: Vec<T>) -> HashMap<usize, T> {
     map
 }
 
+/// Transform a `Vec<T>` into `HashMap<K, T>`, where `key` is derived by `key_fn` from the index and the value of `T`.
+///
+/// If `key_fn` return the same `key` multiple times, the last value is kept
+pub fn vec_to_keyed_hashmap<T, K: Eq + Hash, F: Fn(usize, &T) -> K>(
+    vec: Vec<T>,
+    key_fn: F,
+) -> HashMap<K, T> {
+    let mut map: HashMap<K, T> = HashMap::new();
+
+    for (i, v) in vec.into_iter().enumerate() {
+        map.insert(key_fn(i, &v), v);
+    }
+
+    map
+}
+
+/// Transform a `Vec<T>` into `BTreeMap<K, T>`, where `key` is derived by `key_fn` from the index and the value of `T`.
+///
+/// If `key_fn` return the same `key` multiple times, the last value is kept
+pub fn vec_to_btree<T, K: Ord, F: Fn(usize, &T) -> K>(vec: Vec<T>, key_fn: F) -> BTreeMap<K, T> {
+    let mut map: BTreeMap<K, T> = BTreeMap::new();
+
+    for (i, v) in vec.into_iter().enumerate() {
+        map.insert(key_fn(i, &v), v);
+    }
+
+    map
+}
+
 /// Transform a `Vec<(K, V)>` into `HashMap<K, V>`
 pub fn vec_tuple_to_hashmap<K: Eq + Hash + Debug + Clone, V>(
     vec: Vec<(K, V)>,
@@ -7,6 +7,9 @@ use {
     serde::Serialize,
 };
 
+#[cfg(feature = "ibc")]
+use cosmwasm_std::{IbcMsg, IbcTimeout};
+
 pub fn generate_instantiate_2_addr(
     deps: Deps,
     code_id: u64,
@@ -95,6 +98,50 @@ pub trait WasmMsgBuilder {
 
 impl WasmMsgBuilder for WasmMsg {}
 
+/// Builders for `IbcMsg`. The returned `CosmosMsg` can be wrapped into a `SubMsg` with [CosmosMsgExt]
+///
+/// Requires the `ibc` feature, which enables the `stargate` feature of `cosmwasm-std`
+#[cfg(feature = "ibc")]
+pub trait IbcMsgBuilder {
+    fn build_transfer(
+        channel_id: &str,
+        to_address: &str,
+        amount: Coin,
+        timeout: IbcTimeout,
+    ) -> CosmosMsg {
+        IbcMsg::Transfer {
+            channel_id: channel_id.to_string(),
+            to_address: to_address.to_string(),
+            amount,
+            timeout,
+        }
+        .into()
+    }
+
+    fn build_send_packet<T: Serialize>(
+        channel_id: &str,
+        data: T,
+        timeout: IbcTimeout,
+    ) -> StdResult<CosmosMsg> {
+        Ok(IbcMsg::SendPacket {
+            channel_id: channel_id.to_string(),
+            data: data.into_binary()?,
+            timeout,
+        }
+        .into())
+    }
+
+    fn build_close_channel(channel_id: &str) -> CosmosMsg {
+        IbcMsg::CloseChannel {
+            channel_id: channel_id.to_string(),
+        }
+        .into()
+    }
+}
+
+#[cfg(feature = "ibc")]
+impl IbcMsgBuilder for IbcMsg {}
+
 pub trait CosmosMsgExt {
     fn into_submsg_always(self, reply_id: u64, gas_limit: Option<u64>) -> SubMsg;
     fn into_submsg_on_error(self, reply_id: u64, gas_limit: Option<u64>) -> SubMsg;
@@ -15,3 +15,6 @@ mod test_serde;
 
 #[cfg(test)]
 mod test_utils;
+
+#[cfg(test)]
+mod test_wasm;
@@ -3,8 +3,8 @@ use cosmwasm_std::{BankMsg, Coin, CosmosMsg, Decimal, StdResult};
 use rhaki_cw_plus::{
     math::IntoDecimal,
     serde_value::{
-        std_to_sjw_value, value_to_string, DoubleDeserialize, DoubleValueDeserializeResult,
-        PathKey, SerdeValue, ToCwJson, Value,
+        std_to_sjw_value, value_patch, value_to_string, DoubleDeserialize,
+        DoubleValueDeserializeResult, PathKey, SerdeValue, ToCwJson, Value,
     },
 };
 use serde_json::json;
@@ -121,3 +121,55 @@ fn convert() {
 
     assert_eq!(res, "1.3".into_decimal())
 }
+
+#[test]
+fn patch() {
+    let mut target = json!({
+        "title": "Goodbye!",
+        "author": {"given_name": "John", "family_name": "Doe"},
+        "tags": ["example", "sample"],
+        "content": "This will be unchanged"
+    })
+    .into_cw()
+    .unwrap();
+
+    let patch = json!({
+        "title": "Hello!",
+        "phone_number": "+01-123-456-7890",
+        "author": {"family_name": null},
+        "tags": ["example"]
+    })
+    .into_cw()
+    .unwrap();
+
+    value_patch(&mut target, &patch).unwrap();
+
+    assert_eq!(
+        target,
+        json!({
+            "title": "Hello!",
+            "author": {"given_name": "John"},
+            "tags": ["example"],
+            "content": "This will be unchanged",
+            "phone_number": "+01-123-456-7890"
+        })
+        .into_cw()
+        .unwrap()
+    );
+
+    let mut target = json!({"a": "b"}).into_cw().unwrap();
+
+    value_patch(&mut target, &json!(["c"]).into_cw().unwrap()).unwrap();
+
+    assert_eq!(target, json!(["c"]).into_cw().unwrap());
+
+    let mut target = json!("foo").into_cw().unwrap();
+
+    value_patch(
+        &mut target,
+        &json!({"a": {"b": "c", "d": null}}).into_cw().unwrap(),
+    )
+    .unwrap();
+
+    assert_eq!(target, json!({"a": {"b": "c"}}).into_cw().unwrap());
+}
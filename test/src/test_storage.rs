@@ -4,7 +4,7 @@ mod map {
     use cosmwasm_schema::cw_serde;
     use cosmwasm_std::{testing::mock_dependencies, Order};
     use cw_storage_plus::Map;
-    use rhaki_cw_plus::storage::map::get_items;
+    use rhaki_cw_plus::storage::map::{get_items, key_bounds, max_key, min_key};
 
     #[cw_serde]
     pub struct StructWithMap {
@@ -119,6 +119,25 @@ mod map {
 
         assert_eq!(load_map.map, hash_map)
     }
+
+    #[test]
+    fn key_bounds_test() {
+        let mut deps = mock_dependencies();
+
+        let map: Map<u64, String> = Map::new("test_map");
+
+        assert_eq!(min_key(deps.as_ref().storage, &map), None);
+        assert_eq!(max_key(deps.as_ref().storage, &map), None);
+        assert_eq!(key_bounds(deps.as_ref().storage, &map), None);
+
+        for i in [7_u64, 3, 42, 15] {
+            map.save(deps.as_mut().storage, i, &i.to_string()).unwrap();
+        }
+
+        assert_eq!(min_key(deps.as_ref().storage, &map), Some(3));
+        assert_eq!(max_key(deps.as_ref().storage, &map), Some(42));
+        assert_eq!(key_bounds(deps.as_ref().storage, &map), Some((3, 42)));
+    }
 }
 mod test_multi_index {
     use cosmwasm_schema::cw_serde;
@@ -1,8 +1,10 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use cosmwasm_schema::cw_serde;
 use rhaki_cw_plus::serde_value::Value;
-use rhaki_cw_plus::utils::{vec_to_i_hashmap, vec_tuple_to_hashmap};
+use rhaki_cw_plus::utils::{
+    vec_to_btree, vec_to_i_hashmap, vec_to_keyed_hashmap, vec_tuple_to_hashmap,
+};
 use rhaki_cw_plus::{cw_serde_value, Optionable, SmallerTwin};
 
 #[cw_serde_value]
@@ -64,3 +66,31 @@ fn main() {
 
     assert_eq!(res, map);
 }
+
+#[test]
+fn keyed() {
+    let vec = vec![
+        "first".to_string(),
+        "second".to_string(),
+        "third".to_string(),
+    ];
+
+    let res = vec_to_keyed_hashmap(vec.clone(), |i, v| format!("{i}-{v}"));
+
+    let mut map: HashMap<String, String> = HashMap::new();
+
+    map.insert("0-first".to_string(), "first".to_string());
+    map.insert("1-second".to_string(), "second".to_string());
+    map.insert("2-third".to_string(), "third".to_string());
+
+    assert_eq!(res, map);
+
+    let res = vec_to_btree(vec, |_, v| v.len());
+
+    let mut map: BTreeMap<usize, String> = BTreeMap::new();
+
+    map.insert(5, "third".to_string());
+    map.insert(6, "second".to_string());
+
+    assert_eq!(res, map);
+}
@@ -0,0 +1,57 @@
+use {
+    cosmwasm_std::{
+        to_json_binary, Coin, CosmosMsg, IbcMsg, IbcTimeout, ReplyOn, SubMsg, Timestamp,
+    },
+    rhaki_cw_plus::wasm::{CosmosMsgExt, IbcMsgBuilder},
+    serde_json::json,
+};
+
+#[test]
+fn ibc_msg_builder() {
+    let timeout = IbcTimeout::with_timestamp(Timestamp::from_seconds(100));
+
+    let msg = IbcMsg::build_transfer(
+        "channel-0",
+        "osmo1receiver",
+        Coin::new(100, "uatom"),
+        timeout.clone(),
+    );
+
+    assert_eq!(
+        msg,
+        CosmosMsg::Ibc(IbcMsg::Transfer {
+            channel_id: "channel-0".to_string(),
+            to_address: "osmo1receiver".to_string(),
+            amount: Coin::new(100, "uatom"),
+            timeout: timeout.clone(),
+        })
+    );
+
+    assert_eq!(
+        msg.clone().into_submsg_always(1, None),
+        SubMsg {
+            id: 1,
+            msg,
+            gas_limit: None,
+            reply_on: ReplyOn::Always,
+        }
+    );
+
+    let data = json!({"foo": "bar"});
+
+    assert_eq!(
+        IbcMsg::build_send_packet("channel-1", &data, timeout.clone()).unwrap(),
+        CosmosMsg::Ibc(IbcMsg::SendPacket {
+            channel_id: "channel-1".to_string(),
+            data: to_json_binary(&data).unwrap(),
+            timeout,
+        })
+    );
+
+    assert_eq!(
+        IbcMsg::build_close_channel("channel-2"),
+        CosmosMsg::Ibc(IbcMsg::CloseChannel {
+            channel_id: "channel-2".to_string(),
+        })
+    );
+}